@@ -0,0 +1,106 @@
+// Module for clustering the kNN adjacency into strongly connected components, which
+// group near-duplicate "product families" out of the directed similarity graph
+// (i being a nearest neighbor of j does not guarantee j is a nearest neighbor of i).
+
+use crate::graph::Graph;
+
+impl Graph {
+    /// Computes the strongly connected components of the adjacency graph using an
+    /// iterative Tarjan's algorithm (an explicit stack instead of recursion, so it
+    /// scales to large product catalogs without blowing the call stack).
+    /// Output: each component as a Vector of node indices.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        let n = self.adjacency.len();
+
+        let mut index = vec![None; n];
+        let mut lowlink = vec![0; n];
+        let mut on_stack = vec![false; n];
+        let mut stack = Vec::new();
+        let mut next_index = 0;
+        let mut components = Vec::new();
+
+        // Each work-stack frame tracks the node being visited and how far through its
+        // neighbor list we've gotten, so the DFS can be resumed without recursion.
+        for start in 0..n {
+            if index[start].is_some() {
+                continue;
+            }
+
+            let mut work: Vec<(usize, usize)> = vec![(start, 0)];
+
+            while let Some(&mut (v, ref mut neighbor_pos)) = work.last_mut() {
+                if index[v].is_none() {
+                    index[v] = Some(next_index);
+                    lowlink[v] = next_index;
+                    next_index += 1;
+                    stack.push(v);
+                    on_stack[v] = true;
+                }
+
+                let neighbors = &self.adjacency[v];
+
+                if *neighbor_pos < neighbors.len() {
+                    let (w, _) = neighbors[*neighbor_pos];
+                    *neighbor_pos += 1;
+
+                    if index[w].is_none() {
+                        work.push((w, 0));
+                    } else if on_stack[w] {
+                        lowlink[v] = lowlink[v].min(index[w].unwrap());
+                    }
+                } else {
+                    work.pop();
+
+                    if lowlink[v] == index[v].unwrap() {
+                        let mut component = Vec::new();
+                        loop {
+                            let w = stack.pop().unwrap();
+                            on_stack[w] = false;
+                            component.push(w);
+                            if w == v {
+                                break;
+                            }
+                        }
+                        components.push(component);
+                    }
+
+                    if let Some(&mut (parent, _)) = work.last_mut() {
+                        lowlink[parent] = lowlink[parent].min(lowlink[v]);
+                    }
+                }
+            }
+        }
+
+        components
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Graph;
+
+    #[test]
+    fn test_mutual_pair_forms_one_component() {
+        let adj = vec![vec![(1, 0.9)], vec![(0, 0.9)], vec![]];
+        let g = Graph::new(adj);
+        let mut components = g.strongly_connected_components();
+        for component in components.iter_mut() {
+            component.sort();
+        }
+        components.sort();
+        assert_eq!(components, vec![vec![0, 1], vec![2]]);
+    }
+
+    #[test]
+    fn test_directed_only_pair_forms_separate_components() {
+        // 0 lists 1 as a neighbor, but 1 does not list 0 back.
+        let adj = vec![vec![(1, 0.9)], vec![]];
+        let g = Graph::new(adj);
+        let mut components = g.strongly_connected_components();
+        for component in components.iter_mut() {
+            component.sort();
+        }
+        components.sort();
+        assert_eq!(components, vec![vec![0], vec![1]]);
+    }
+}
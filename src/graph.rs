@@ -1,8 +1,12 @@
 // Module for the Graph struct, which is useful later on for the recommendation system.
 
-type AdjList = Vec<Vec<usize>>;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
 
-/// Simple struct for storing adjacency lists and reading data from them.
+type AdjList = Vec<Vec<(usize, f64)>>;
+
+/// Simple struct for storing weighted adjacency lists and reading data from them.
+/// Each entry in `adjacency[i]` is `(j, similarity)`, the cosine similarity between i and j.
 pub struct Graph {
     pub adjacency: AdjList,
 }
@@ -18,19 +22,97 @@ impl Graph {
     /// Gets all the neighbors of a specified node within the Graph.
     /// Input: a specified node number.
     /// Output: all the neighbors of the given node.
-    pub fn neighbors(&self, node: usize) -> Option<&Vec<usize>> {
+    pub fn neighbors(&self, node: usize) -> Option<&Vec<(usize, f64)>> {
         self.adjacency.get(node)
     }
 }
 
+/// Explores beyond immediate neighbors by running Dijkstra over the similarity graph,
+/// treating `1.0 - similarity` (clamped to >= 0) as edge distance.
+/// Inputs: a Graph, the starting node and a value k.
+/// Output: the first k distinct nodes popped off the heap, other than the start node itself.
+pub fn recommend_multi_hop(graph: &Graph, node: usize, k: usize) -> Vec<usize> {
+    let mut dist = vec![f64::INFINITY; graph.adjacency.len()];
+    let mut visited = vec![false; graph.adjacency.len()];
+    let mut heap = BinaryHeap::new();
+    let mut result = Vec::new();
+
+    dist[node] = 0.0;
+    heap.push(Reverse((OrderedDist(0.0), node)));
+
+    while let Some(Reverse((OrderedDist(d), u))) = heap.pop() {
+        if visited[u] {
+            continue;
+        }
+        visited[u] = true;
+
+        if u != node {
+            result.push(u);
+            if result.len() == k {
+                break;
+            }
+        }
+
+        if let Some(neighbors) = graph.neighbors(u) {
+            for &(v, sim) in neighbors {
+                if visited[v] {
+                    continue;
+                }
+                let weight = (1.0 - sim).max(0.0);
+                let new_dist = d + weight;
+                if new_dist < dist[v] {
+                    dist[v] = new_dist;
+                    heap.push(Reverse((OrderedDist(new_dist), v)));
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// Wraps an `f64` distance so it can be ordered inside a `BinaryHeap`.
+/// Distances here are always finite, non-negative similarity-derived weights.
+#[derive(PartialEq)]
+struct OrderedDist(f64);
+
+impl Eq for OrderedDist {}
+
+impl PartialOrd for OrderedDist {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedDist {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.partial_cmp(&other.0).unwrap_or(std::cmp::Ordering::Equal)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Graph;
+    use super::{Graph, recommend_multi_hop};
 
     #[test]
     fn test_neighbors() {
-        let adj = vec![vec![1, 2], vec![0], vec![0]];
+        let adj = vec![vec![(1, 0.9), (2, 0.5)], vec![(0, 0.9)], vec![(0, 0.5)]];
+        let g = Graph::new(adj);
+        assert_eq!(g.neighbors(0), Some(&vec![(1, 0.9), (2, 0.5)]));
+    }
+
+    #[test]
+    fn test_recommend_multi_hop() {
+        // 0 is directly close to 1, and 1 is close to 3, so 3 should surface as a
+        // transitive match for 0 even though it isn't a direct neighbor.
+        let adj = vec![
+            vec![(1, 0.9)],
+            vec![(0, 0.9), (3, 0.8)],
+            vec![(3, 0.95)],
+            vec![(1, 0.8), (2, 0.95)],
+        ];
         let g = Graph::new(adj);
-        assert_eq!(g.neighbors(0), Some(&vec![1, 2]));
+        let recs = recommend_multi_hop(&g, 0, 2);
+        assert_eq!(recs, vec![1, 3]);
     }
 }
\ No newline at end of file
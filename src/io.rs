@@ -0,0 +1,191 @@
+// Module for reading and writing the feature matrix and adjacency graph as Matrix
+// Market files, so preprocessed data can be cached or handed off to external tooling.
+
+use std::error::Error;
+use std::fmt;
+use std::fs;
+
+use ndarray::Array2;
+
+/// A weighted directed adjacency list: `adjacency[i]` is i's `(j, similarity)` edges.
+type AdjList = Vec<Vec<(usize, f64)>>;
+
+/// Error returned when a Matrix Market file is malformed.
+#[derive(Debug)]
+pub struct MatrixMarketError(String);
+
+impl fmt::Display for MatrixMarketError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid Matrix Market file: {}", self.0)
+    }
+}
+
+impl Error for MatrixMarketError {}
+
+/// Writes a dense feature matrix to the Matrix Market coordinate (real, general) format,
+/// emitting only the nonzero entries.
+/// Inputs: the matrix and an output file path.
+/// Output: nothing, or an error if the file could not be written.
+pub fn write_matrix_market(matrix: &Array2<f64>, path: &str) -> Result<(), Box<dyn Error>> {
+    let mut body = String::new();
+    body.push_str("%%MatrixMarket matrix coordinate real general\n");
+
+    let nonzeros: Vec<(usize, usize, f64)> = matrix.indexed_iter()
+        .filter(|&(_, &v)| v != 0.0)
+        .map(|((i, j), &v)| (i, j, v))
+        .collect();
+
+    body.push_str(&format!("{} {} {}\n", matrix.nrows(), matrix.ncols(), nonzeros.len()));
+
+    for (i, j, v) in nonzeros {
+        // Matrix Market indices are 1-based.
+        body.push_str(&format!("{} {} {}\n", i + 1, j + 1, v));
+    }
+
+    fs::write(path, body)?;
+    Ok(())
+}
+
+/// Reads a dense feature matrix back from the Matrix Market coordinate (real, general)
+/// format written by `write_matrix_market`.
+/// Input: the path of a Matrix Market file.
+/// Output: the reconstructed matrix, or an error if the file is malformed.
+pub fn read_matrix_market(path: &str) -> Result<Array2<f64>, Box<dyn Error>> {
+    let contents = fs::read_to_string(path)?;
+    let mut lines = contents.lines().filter(|line| !line.starts_with('%'));
+
+    let dims = lines.next().ok_or_else(|| MatrixMarketError("missing dimension line".to_string()))?;
+    let mut dims_iter = dims.split_whitespace();
+    let rows: usize = dims_iter.next().ok_or_else(|| MatrixMarketError("missing row count".to_string()))?.parse()
+        .map_err(|_| MatrixMarketError("row count is not a number".to_string()))?;
+    let cols: usize = dims_iter.next().ok_or_else(|| MatrixMarketError("missing column count".to_string()))?.parse()
+        .map_err(|_| MatrixMarketError("column count is not a number".to_string()))?;
+    let nnz: usize = dims_iter.next().ok_or_else(|| MatrixMarketError("missing entry count".to_string()))?.parse()
+        .map_err(|_| MatrixMarketError("entry count is not a number".to_string()))?;
+
+    let mut matrix = Array2::<f64>::zeros((rows, cols));
+    let mut entries = 0;
+
+    for line in lines {
+        let mut parts = line.split_whitespace();
+        let i: usize = parts.next().ok_or_else(|| MatrixMarketError("missing row index".to_string()))?.parse()
+            .map_err(|_| MatrixMarketError("row index is not a number".to_string()))?;
+        let j: usize = parts.next().ok_or_else(|| MatrixMarketError("missing column index".to_string()))?.parse()
+            .map_err(|_| MatrixMarketError("column index is not a number".to_string()))?;
+        let v: f64 = parts.next().ok_or_else(|| MatrixMarketError("missing value".to_string()))?.parse()
+            .map_err(|_| MatrixMarketError("value is not a number".to_string()))?;
+
+        if i == 0 || j == 0 || i > rows || j > cols {
+            return Err(Box::new(MatrixMarketError(format!("entry ({}, {}) out of bounds", i, j))));
+        }
+
+        matrix[[i - 1, j - 1]] = v;
+        entries += 1;
+    }
+
+    if entries != nnz {
+        return Err(Box::new(MatrixMarketError(format!("expected {} entries, found {}", nnz, entries))));
+    }
+
+    Ok(matrix)
+}
+
+/// Writes a directed adjacency list to the Matrix Market coordinate (pattern, general)
+/// format, emitting `i j` for each edge with no value column.
+/// Inputs: the adjacency list and an output file path.
+/// Output: nothing, or an error if the file could not be written.
+pub fn write_adjacency_market(adjacency: &AdjList, path: &str) -> Result<(), Box<dyn Error>> {
+    let mut body = String::new();
+    body.push_str("%%MatrixMarket matrix coordinate pattern general\n");
+
+    let n = adjacency.len();
+    let nnz: usize = adjacency.iter().map(|neighbors| neighbors.len()).sum();
+    body.push_str(&format!("{} {} {}\n", n, n, nnz));
+
+    for (i, neighbors) in adjacency.iter().enumerate() {
+        for &(j, _) in neighbors {
+            // Matrix Market indices are 1-based.
+            body.push_str(&format!("{} {}\n", i + 1, j + 1));
+        }
+    }
+
+    fs::write(path, body)?;
+    Ok(())
+}
+
+/// Reads a directed adjacency pattern back from the Matrix Market coordinate (pattern,
+/// general) format written by `write_adjacency_market`. Since the pattern format carries
+/// no weights, every edge is reconstructed with a similarity of 1.0.
+/// Input: the path of a Matrix Market file.
+/// Output: the reconstructed adjacency list, or an error if the file is malformed.
+pub fn read_adjacency_market(path: &str) -> Result<AdjList, Box<dyn Error>> {
+    let contents = fs::read_to_string(path)?;
+    let mut lines = contents.lines().filter(|line| !line.starts_with('%'));
+
+    let dims = lines.next().ok_or_else(|| MatrixMarketError("missing dimension line".to_string()))?;
+    let mut dims_iter = dims.split_whitespace();
+    let rows: usize = dims_iter.next().ok_or_else(|| MatrixMarketError("missing row count".to_string()))?.parse()
+        .map_err(|_| MatrixMarketError("row count is not a number".to_string()))?;
+    let _cols: usize = dims_iter.next().ok_or_else(|| MatrixMarketError("missing column count".to_string()))?.parse()
+        .map_err(|_| MatrixMarketError("column count is not a number".to_string()))?;
+    let nnz: usize = dims_iter.next().ok_or_else(|| MatrixMarketError("missing entry count".to_string()))?.parse()
+        .map_err(|_| MatrixMarketError("entry count is not a number".to_string()))?;
+
+    let mut adjacency = vec![Vec::new(); rows];
+    let mut entries = 0;
+
+    for line in lines {
+        let mut parts = line.split_whitespace();
+        let i: usize = parts.next().ok_or_else(|| MatrixMarketError("missing row index".to_string()))?.parse()
+            .map_err(|_| MatrixMarketError("row index is not a number".to_string()))?;
+        let j: usize = parts.next().ok_or_else(|| MatrixMarketError("missing column index".to_string()))?.parse()
+            .map_err(|_| MatrixMarketError("column index is not a number".to_string()))?;
+
+        if i == 0 || j == 0 || i > rows || j > rows {
+            return Err(Box::new(MatrixMarketError(format!("entry ({}, {}) out of bounds", i, j))));
+        }
+
+        adjacency[i - 1].push((j - 1, 1.0));
+        entries += 1;
+    }
+
+    if entries != nnz {
+        return Err(Box::new(MatrixMarketError(format!("expected {} entries, found {}", nnz, entries))));
+    }
+
+    Ok(adjacency)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{write_matrix_market, read_matrix_market, write_adjacency_market, read_adjacency_market};
+    use ndarray::array;
+
+    #[test]
+    fn test_matrix_market_round_trip() {
+        let matrix = array![[1.0, 0.0], [0.0, 2.5]];
+        write_matrix_market(&matrix, "test_matrix.mtx").unwrap();
+        let read_back = read_matrix_market("test_matrix.mtx").unwrap();
+        assert_eq!(matrix, read_back);
+    }
+
+    #[test]
+    fn test_adjacency_market_round_trip() {
+        let adjacency = vec![vec![(1, 0.9), (2, 0.5)], vec![(0, 0.9)], vec![]];
+        write_adjacency_market(&adjacency, "test_adjacency.mtx").unwrap();
+        let read_back = read_adjacency_market("test_adjacency.mtx").unwrap();
+        assert_eq!(read_back, vec![vec![(1, 1.0), (2, 1.0)], vec![(0, 1.0)], vec![]]);
+    }
+
+    #[test]
+    fn test_read_matrix_market_rejects_malformed_dimension_line() {
+        std::fs::write("test_bad.mtx", "%%MatrixMarket matrix coordinate real general\nnot a dimension line\n").unwrap();
+        assert!(read_matrix_market("test_bad.mtx").is_err());
+    }
+
+    #[test]
+    fn test_read_matrix_market_rejects_entry_count_mismatch() {
+        std::fs::write("test_mismatch.mtx", "%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1.0\n").unwrap();
+        assert!(read_matrix_market("test_mismatch.mtx").is_err());
+    }
+}
@@ -1,9 +1,16 @@
 mod preprocessing;
 mod graph;
+mod naive_bayes;
+mod io;
+mod components;
+mod synthetic;
 
-use preprocessing::{load_and_preprocess, build_graph_from_features, load_metadata};
-use graph::Graph;
-use std::io;
+use std::time::Instant;
+
+use preprocessing::{load_and_preprocess, load_and_preprocess_private, build_graph_from_features, build_graph_fastpair, load_metadata, Item};
+use graph::{Graph, recommend_multi_hop};
+use naive_bayes::CategoricalNB;
+use synthetic::DatasetConfig;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let path = "fashion_products.csv";
@@ -11,44 +18,229 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // This can be changed depending on your preference
     let k_neighbors = 5;
 
-    println!("Provide the Product ID for the product you would like recommendations for:");
-
-    // Gets the user input and converts to node number
-    let mut input = String::new(); 
-    io::stdin().read_line(&mut input).expect("Failed to read line"); 
-    let input = input.trim(); 
-
-    let mut target_node: usize = input.parse()?;
-    target_node -= 1;
-
     // Parameters for recommendations. Can also be changed based on preference
     let numeric = ["Price", "Rating"];
     let categorical = ["Brand", "Size"];
 
+    println!("Enter a mode: 'r' recommendations, 'm' multi-hop recommendations, 'c' product family clusters, 'p' predict category, 'x' private recommendations, 'e' export to Matrix Market, 'i' import from Matrix Market, 'b' benchmark graph builders:");
+
+    let mut mode = String::new();
+    std::io::stdin().read_line(&mut mode).expect("Failed to read line");
+    let mode = mode.trim();
+
+    if mode == "b" {
+        return run_benchmark();
+    }
+
+    if mode == "i" {
+        return import_matrix_market();
+    }
+
+    if mode == "x" {
+        let items = load_metadata(path)?;
+        return print_private_recommendations(path, &numeric, &categorical, &items, k_neighbors);
+    }
+
     let items = load_metadata(path)?;
 
+    if mode == "p" {
+        predict_category(&items);
+        return Ok(());
+    }
+
     // Sorting, encoding data and building the recommendations
     let features = load_and_preprocess(path, &numeric, &categorical)?;
     let adjacency = build_graph_from_features(&features, k_neighbors);
     let graph = Graph::new(adjacency);
 
-    let recs = recommend(&graph, target_node, k_neighbors);
+    match mode {
+        "m" => print_multi_hop_recommendations(&graph, &items, k_neighbors)?,
+        "c" => print_clusters(&graph, &items),
+        "e" => export_matrix_market(&features, &graph)?,
+        _ => print_recommendations(&graph, &items, k_neighbors)?,
+    }
+
+    Ok(())
+}
+
+/// Prompts for a single Product ID and prints its top-k recommendations.
+fn print_recommendations(graph: &Graph, items: &[Item], k_neighbors: usize) -> Result<(), Box<dyn std::error::Error>> {
+    println!("Provide the Product ID for the product you would like recommendations for:");
+
+    // Gets the user input and converts to node number
+    let mut input = String::new();
+    std::io::stdin().read_line(&mut input).expect("Failed to read line");
+    let input = input.trim();
+
+    let mut target_node: usize = input.parse()?;
+    target_node -= 1;
+
+    let recs = recommend(graph, target_node, k_neighbors);
 
     println!("Recommendations for {} from {}:", items[target_node].name, items[target_node].brand);
+    print_items(items, &recs);
 
-    for idx in recs {
-        let item = &items[idx];
-        println!("Product ID: {:>3}, Name: {:>7}, Brand: {}, Category: {:>15}, Price: {:>3}, Rating: {:.3}, Color: {:>6}, Size: {}", item.product, item.name, item.brand, item.category, item.price, item.rating, item.color, item.size);
+    Ok(())
+}
+
+/// Prompts for a single Product ID and prints its top-k recommendations, exploring
+/// beyond direct neighbors via `recommend_multi_hop` to surface transitive matches.
+fn print_multi_hop_recommendations(graph: &Graph, items: &[Item], k_neighbors: usize) -> Result<(), Box<dyn std::error::Error>> {
+    println!("Provide the Product ID for the product you would like multi-hop recommendations for:");
+
+    let mut input = String::new();
+    std::io::stdin().read_line(&mut input).expect("Failed to read line");
+    let input = input.trim();
+
+    let mut target_node: usize = input.parse()?;
+    target_node -= 1;
+
+    let recs = recommend_multi_hop(graph, target_node, k_neighbors);
+
+    println!("Multi-hop recommendations for {} from {}:", items[target_node].name, items[target_node].brand);
+    print_items(items, &recs);
+
+    Ok(())
+}
+
+/// Prints every cluster of mutually-similar products (strongly connected components
+/// of the kNN adjacency) with their names and brands.
+fn print_clusters(graph: &Graph, items: &[Item]) {
+    let components = graph.strongly_connected_components();
+
+    for (i, component) in components.iter().enumerate() {
+        println!("Product family {}:", i + 1);
+        for &idx in component {
+            let item = &items[idx];
+            println!("  Product ID: {:>3}, Name: {:>7}, Brand: {}", item.product, item.name, item.brand);
+        }
     }
+}
+
+/// Fits a Categorical Naive Bayes model on the catalog's Brand/Size -> Category
+/// pattern, then predicts a Category for a Brand/Size pair the user provides.
+fn predict_category(items: &[Item]) {
+    let nb = CategoricalNB::fit(items, &["Brand", "Size"], "Category");
+
+    println!("Enter a Brand to predict the most likely Category for:");
+    let mut brand = String::new();
+    std::io::stdin().read_line(&mut brand).expect("Failed to read line");
+
+    println!("Enter a Size to predict the most likely Category for:");
+    let mut size = String::new();
+    std::io::stdin().read_line(&mut size).expect("Failed to read line");
+
+    let probe = Item {
+        product: 0,
+        name: String::new(),
+        brand: brand.trim().to_string(),
+        category: String::new(),
+        price: 0,
+        rating: 0.0,
+        color: String::new(),
+        size: size.trim().to_string(),
+    };
+
+    println!("Predicted Category: {}", nb.predict(&probe));
+}
+
+/// Prompts for a privacy budget, rebuilds the feature matrix with Laplace noise
+/// added to the numeric columns, and prints recommendations from the noisy graph.
+fn print_private_recommendations(path: &str, numeric: &[&str], categorical: &[&str], items: &[Item], k_neighbors: usize) -> Result<(), Box<dyn std::error::Error>> {
+    println!("Enter a privacy budget (epsilon) to apply to each numeric column (smaller = more private):");
+
+    let mut input = String::new();
+    std::io::stdin().read_line(&mut input).expect("Failed to read line");
+    let epsilon: f64 = input.trim().parse()?;
+
+    let epsilons = vec![epsilon; numeric.len()];
+    let features = load_and_preprocess_private(path, numeric, categorical, &epsilons)?;
+    let adjacency = build_graph_from_features(&features, k_neighbors);
+    let graph = Graph::new(adjacency);
+
+    print_recommendations(&graph, items, k_neighbors)
+}
+
+/// Writes the current feature matrix and similarity graph to Matrix Market files.
+fn export_matrix_market(features: &ndarray::Array2<f64>, graph: &Graph) -> Result<(), Box<dyn std::error::Error>> {
+    io::write_matrix_market(features, "features.mtx")?;
+    io::write_adjacency_market(&graph.adjacency, "adjacency.mtx")?;
+    println!("Wrote features.mtx and adjacency.mtx");
 
     Ok(())
 }
 
+/// Reads back the feature matrix and similarity graph previously written by
+/// `export_matrix_market` and prints the product family clusters found in it.
+fn import_matrix_market() -> Result<(), Box<dyn std::error::Error>> {
+    let features = io::read_matrix_market("features.mtx")?;
+    let adjacency = io::read_adjacency_market("adjacency.mtx")?;
+    let graph = Graph::new(adjacency);
+
+    println!("Imported a {}x{} feature matrix and a {}-node graph from Matrix Market files.", features.nrows(), features.ncols(), graph.adjacency.len());
+
+    let items = load_metadata("fashion_products.csv")?;
+    print_clusters(&graph, &items);
+
+    Ok(())
+}
+
+/// Generates a synthetic catalog at a user-chosen size and times the brute-force and
+/// FastPair-style graph builders against each other, so `build_graph_from_features`
+/// and `build_graph_fastpair` can be benchmarked at arbitrary scale.
+fn run_benchmark() -> Result<(), Box<dyn std::error::Error>> {
+    println!("Enter the number of synthetic products to benchmark with:");
+
+    let mut input = String::new();
+    std::io::stdin().read_line(&mut input).expect("Failed to read line");
+    let count: usize = input.trim().parse()?;
+
+    let config = DatasetConfig {
+        count,
+        price_range: (10, 500),
+        rating_range: (1.0, 5.0),
+        brands: &["Nike", "Adidas", "Zara", "Puma", "H&M"],
+        categories: &["Shoes", "Shirts", "Pants", "Jackets"],
+        colors: &["Black", "White", "Red", "Blue"],
+        sizes: &["Small", "Medium", "Large"],
+        seed: 42,
+    };
+
+    let items = synthetic::generate_dataset(&config);
+    let bench_path = "synthetic_benchmark.csv";
+    synthetic::write_csv(&items, bench_path)?;
+
+    let numeric = ["Price", "Rating"];
+    let categorical = ["Brand", "Size"];
+    let features = load_and_preprocess(bench_path, &numeric, &categorical)?;
+
+    let brute_start = Instant::now();
+    build_graph_from_features(&features, 5);
+    let brute_elapsed = brute_start.elapsed();
+
+    let fastpair_start = Instant::now();
+    build_graph_fastpair(&features, 5);
+    let fastpair_elapsed = fastpair_start.elapsed();
+
+    println!("Brute-force build over {} products took {:?}", count, brute_elapsed);
+    println!("FastPair build over {} products took {:?}", count, fastpair_elapsed);
+
+    Ok(())
+}
+
+/// Prints each recommended item's details in the shared output format.
+fn print_items(items: &[Item], indices: &[usize]) {
+    for &idx in indices {
+        let item = &items[idx];
+        println!("Product ID: {:>3}, Name: {:>7}, Brand: {}, Category: {:>15}, Price: {:>3}, Rating: {:.3}, Color: {:>6}, Size: {}", item.product, item.name, item.brand, item.category, item.price, item.rating, item.color, item.size);
+    }
+}
+
 /// Takes the top k neighbors of a given node and turns into a Vector.
 /// Inputs: a Graph object, a node number and a value k
 /// Output: a Vector of usizes, which is all node numbers.
 fn recommend(graph: &Graph, node: usize, k: usize) -> Vec<usize> {
-    graph.neighbors(node).map(|neigh| neigh.iter().cloned().take(k).collect()).unwrap_or_default()
+    graph.neighbors(node).map(|neigh| neigh.iter().map(|&(j, _)| j).take(k).collect()).unwrap_or_default()
 }
 
 #[cfg(test)]
@@ -58,9 +250,9 @@ mod tests {
 
     #[test]
     fn test_recommend() {
-        let adj = vec![vec![1, 2], vec![0], vec![0]];
+        let adj = vec![vec![(1, 0.9), (2, 0.5)], vec![(0, 0.9)], vec![(0, 0.5)]];
         let g = Graph::new(adj);
         assert_eq!(recommend(&g, 0, 1), vec![1]);
         assert_eq!(recommend(&g, 1, 5), vec![0]);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,172 @@
+// Module for predicting a categorical field (e.g. Category) from a product's other
+// categorical fields, complementing the similarity-graph recommendations.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::preprocessing::Item;
+
+/// A Categorical Naive Bayes classifier fit over an `Item`'s categorical fields.
+/// Stores per-feature, per-class category counts plus log class priors so `predict`
+/// can score `log P(class) + sum(log P(feature_value | class))` for each class.
+pub struct CategoricalNB {
+    classes: Vec<String>,
+    log_priors: HashMap<String, f64>,
+    feature_cols: Vec<String>,
+    category_vecs: HashMap<String, Vec<String>>,
+    feature_counts: HashMap<String, HashMap<String, HashMap<String, usize>>>,
+    class_counts: HashMap<String, usize>,
+}
+
+impl CategoricalNB {
+    /// Fits the model by counting, for each target class, how often each feature
+    /// column takes on each value, and computing log class priors from class frequency.
+    /// Inputs: the training items, the feature column names to condition on, and the
+    /// target column name to predict.
+    /// Output: a fitted CategoricalNB.
+    pub fn fit(items: &[Item], feature_cols: &[&str], target_col: &str) -> Self {
+        let mut class_counts: HashMap<String, usize> = HashMap::new();
+        let mut category_values: HashMap<String, HashSet<String>> = HashMap::new();
+        let mut feature_counts: HashMap<String, HashMap<String, HashMap<String, usize>>> = HashMap::new();
+
+        for item in items {
+            let class = item_field(item, target_col);
+            *class_counts.entry(class.clone()).or_insert(0) += 1;
+
+            for &col in feature_cols {
+                let value = item_field(item, col);
+                category_values.entry(col.to_string()).or_default().insert(value.clone());
+                *feature_counts.entry(col.to_string()).or_default()
+                    .entry(class.clone()).or_default()
+                    .entry(value).or_insert(0) += 1;
+            }
+        }
+
+        // Sort category vectors, mirroring `load_and_preprocess`'s `category_vecs`.
+        let mut category_vecs: HashMap<String, Vec<String>> = HashMap::new();
+        for (col, set) in category_values {
+            let mut vec: Vec<_> = set.into_iter().collect();
+            vec.sort();
+            category_vecs.insert(col, vec);
+        }
+
+        let total: usize = class_counts.values().sum();
+        let log_priors: HashMap<String, f64> = class_counts.iter()
+            .map(|(class, &count)| (class.clone(), (count as f64 / total as f64).ln()))
+            .collect();
+
+        // Sorted so that `predict`'s tie-break (first in `classes` wins) is
+        // deterministic across runs, rather than depending on HashMap's random
+        // iteration order.
+        let mut classes: Vec<String> = class_counts.keys().cloned().collect();
+        classes.sort();
+
+        CategoricalNB {
+            classes,
+            log_priors,
+            feature_cols: feature_cols.iter().map(|s| s.to_string()).collect(),
+            category_vecs,
+            feature_counts,
+            class_counts,
+        }
+    }
+
+    /// Predicts the most likely target class for an item by taking the argmax over
+    /// classes of the log prior plus the summed log feature likelihoods.
+    /// Input: an item to classify.
+    /// Output: the predicted class label.
+    pub fn predict(&self, item: &Item) -> String {
+        let mut best_class = self.classes[0].clone();
+        let mut best_score = f64::NEG_INFINITY;
+
+        for class in &self.classes {
+            let mut score = self.log_priors[class];
+
+            for col in &self.feature_cols {
+                let value = item_field(item, col);
+                let num_categories = self.category_vecs[col].len();
+                let class_total = self.class_counts[class];
+
+                let count = self.feature_counts.get(col)
+                    .and_then(|by_class| by_class.get(class))
+                    .and_then(|by_value| by_value.get(&value))
+                    .copied()
+                    .unwrap_or(0);
+
+                // Laplace (add-one) smoothing; this falls back to the smoothed
+                // probability for feature values never seen in training, so a previously
+                // unseen Brand/Color/etc. never drives the score to -inf.
+                let prob = (count as f64 + 1.0) / (class_total as f64 + num_categories as f64);
+                score += prob.ln();
+            }
+
+            if score > best_score {
+                best_score = score;
+                best_class = class.clone();
+            }
+        }
+
+        best_class
+    }
+}
+
+/// Reads a named column off an `Item`, converting numeric fields to their string form.
+/// Input: an item and a column name matching one of `Item`'s fields.
+/// Output: the field's value as a String.
+fn item_field(item: &Item, col: &str) -> String {
+    match col {
+        "Product" => item.product.to_string(),
+        "Name" => item.name.clone(),
+        "Brand" => item.brand.clone(),
+        "Category" => item.category.clone(),
+        "Price" => item.price.to_string(),
+        "Rating" => item.rating.to_string(),
+        "Color" => item.color.clone(),
+        "Size" => item.size.clone(),
+        _ => panic!("unknown column: {}", col),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CategoricalNB;
+    use crate::preprocessing::Item;
+
+    fn item(brand: &str, size: &str, category: &str) -> Item {
+        Item {
+            product: 1,
+            name: "n".to_string(),
+            brand: brand.to_string(),
+            category: category.to_string(),
+            price: 10,
+            rating: 4.0,
+            color: "Black".to_string(),
+            size: size.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_predict_matches_training_pattern() {
+        let items = vec![
+            item("Nike", "Small", "Shoes"),
+            item("Nike", "Medium", "Shoes"),
+            item("Zara", "Small", "Shirts"),
+            item("Zara", "Medium", "Shirts"),
+        ];
+        let nb = CategoricalNB::fit(&items, &["Brand"], "Category");
+
+        assert_eq!(nb.predict(&item("Nike", "Small", "")), "Shoes");
+        assert_eq!(nb.predict(&item("Zara", "Small", "")), "Shirts");
+    }
+
+    #[test]
+    fn test_predict_unseen_feature_value_does_not_panic() {
+        let items = vec![
+            item("Nike", "Small", "Shoes"),
+            item("Zara", "Small", "Shirts"),
+        ];
+        let nb = CategoricalNB::fit(&items, &["Brand"], "Category");
+
+        let prediction = nb.predict(&item("Puma", "Small", ""));
+        assert!(prediction == "Shoes" || prediction == "Shirts");
+    }
+}
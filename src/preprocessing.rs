@@ -3,7 +3,9 @@
 use std::error::Error;
 use std::collections::{HashMap, HashSet};
 use csv::Reader;
-use ndarray::Array2;
+use ndarray::{Array1, Array2, ArrayView1};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 /// Passes through the data twice, sorting data into columns then normalizing/encoding into one-hot vectors after.
 /// Inputs: a file path, numeric column labels and categorical column labels.
@@ -41,7 +43,7 @@ pub fn load_and_preprocess(path: &str, numeric_cols: &[&str], categorical_cols:
         for &col in categorical_cols {
             let index = header_map[col];
             categorical_values.entry(col.to_string())
-                .or_insert_with(HashSet::new)
+                .or_default()
                 .insert(rec[index].to_string());
         }
     }
@@ -95,6 +97,43 @@ pub fn load_and_preprocess(path: &str, numeric_cols: &[&str], categorical_cols:
     Ok(mat)
 }
 
+/// Same as `load_and_preprocess`, but perturbs the scaled numeric columns with
+/// calibrated Laplace noise before returning the matrix, for privacy-sensitive
+/// catalogs (e.g. per-seller pricing). The categorical one-hot encoding is left
+/// untouched. Implements the Laplace mechanism: after min-max scaling a numeric
+/// column to [0, 1] (sensitivity = 1), noise is drawn from Laplace(0, 1/epsilon) by
+/// sampling `u ~ Uniform(-0.5, 0.5)` and computing `-(1/epsilon) * sign(u) * ln(1 - 2|u|)`,
+/// then the result is re-clamped to [0, 1]. Smaller `epsilon` means more noise/privacy.
+/// Inputs: a file path, numeric column labels, categorical column labels, and a
+/// per-column privacy budget matching `numeric_cols` in length.
+/// Output: a noisy feature matrix, or an error if the budget doesn't match `numeric_cols`.
+pub fn load_and_preprocess_private(
+    path: &str,
+    numeric_cols: &[&str],
+    categorical_cols: &[&str],
+    epsilon: &[f64],
+) -> Result<Array2<f64>, Box<dyn Error>> {
+    if epsilon.len() != numeric_cols.len() {
+        return Err(format!(
+            "expected {} epsilon values (one per numeric column), got {}",
+            numeric_cols.len(), epsilon.len()
+        ).into());
+    }
+
+    let mut mat = load_and_preprocess(path, numeric_cols, categorical_cols)?;
+
+    let mut rng = rand::thread_rng();
+    for (j, &eps) in epsilon.iter().enumerate() {
+        for i in 0..mat.nrows() {
+            let u: f64 = rng.gen_range(-0.5..0.5);
+            let noise = -(1.0 / eps) * u.signum() * (1.0 - 2.0 * u.abs()).ln();
+            mat[[i, j]] = (mat[[i, j]] + noise).clamp(0.0, 1.0);
+        }
+    }
+
+    Ok(mat)
+}
+
 /// Stores the different labels of each product as fields, useful later for printing outputs.
 pub struct Item {
     pub product: i64,
@@ -133,11 +172,11 @@ pub fn load_metadata(path: &str) -> Result<Vec<Item>, Box<dyn Error>> {
     Ok(items)
 }
 
-/// Takes the previously created feature matrix and converts into an adjacency list.
+/// Takes the previously created feature matrix and converts into a weighted adjacency list.
 /// Inputs: a feature matrix and a value k.
-/// Output: a Vector of Vectors of usizes.
+/// Output: a Vector of Vectors of (usize, f64), where the f64 is the cosine similarity.
 /// Loops through all of the features and calculates cosine similarity to every other node.
-pub fn build_graph_from_features(features: &Array2<f64>, k: usize) -> Vec<Vec<usize>> {
+pub fn build_graph_from_features(features: &Array2<f64>, k: usize) -> Vec<Vec<(usize, f64)>> {
     let n = features.nrows();
     let mut graph = vec![Vec::new(); n];
 
@@ -155,16 +194,109 @@ pub fn build_graph_from_features(features: &Array2<f64>, k: usize) -> Vec<Vec<us
 
         // Sorts nodes in descending order of highest similarity
         sim_list.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
-        graph[i] = sim_list.into_iter().take(k).map(|(j, _)| j).collect();
+        graph[i] = sim_list.into_iter().take(k).collect();
     }
 
     graph
 }
 
+/// Number of random hyperplanes combined into one LSH bucket key; higher means
+/// smaller, more precise buckets but a higher chance of missing a true neighbor.
+const FASTPAIR_HYPERPLANES: usize = 8;
+
+/// Number of independent hash tables; a point's candidates are the union of its
+/// bucket across every table, which boosts recall beyond a single table.
+const FASTPAIR_TABLES: usize = 4;
+
+/// Fixed seed for the random hyperplanes so `build_graph_fastpair` is reproducible.
+const FASTPAIR_SEED: u64 = 0x_FA57_9A1A;
+
+/// Projects a row onto a set of random hyperplanes and packs the signs into a bucket key.
+fn lsh_bucket_key(row: ArrayView1<f64>, hyperplanes: &[Array1<f64>]) -> u32 {
+    let mut key = 0u32;
+    for (bit, plane) in hyperplanes.iter().enumerate() {
+        if row.dot(plane) >= 0.0 {
+            key |= 1 << bit;
+        }
+    }
+    key
+}
+
+/// Alternative to `build_graph_from_features` that avoids the full O(n^2) pairwise
+/// scan. Bucketed nearest-neighbor search via locality-sensitive hashing: points are
+/// hashed into buckets by the sign of their projection onto a handful of random
+/// hyperplanes, across several independent hash tables, so that nearby points (by
+/// cosine similarity) collide into the same bucket far more often than distant ones.
+/// A node's k nearest neighbors are then found by scanning only the union of its
+/// bucket's members across tables, instead of every other point, falling back to a
+/// full scan of the remaining points only if that candidate set can't fill k.
+/// Inputs: a feature matrix and a value k.
+/// Output: the same adjacency structure as `build_graph_from_features`.
+pub fn build_graph_fastpair(features: &Array2<f64>, k: usize) -> Vec<Vec<(usize, f64)>> {
+    build_graph_fastpair_counting(features, k).0
+}
+
+/// Same as `build_graph_fastpair`, but also returns the total number of cosine-similarity
+/// comparisons performed across all nodes, so a test can assert the bucketing keeps that
+/// count sub-quadratic without depending on flaky wall-clock timing.
+fn build_graph_fastpair_counting(features: &Array2<f64>, k: usize) -> (Vec<Vec<(usize, f64)>>, usize) {
+    let n = features.nrows();
+    let dim = features.ncols();
+    let norms: Vec<f64> = (0..n).map(|i| features.row(i).dot(&features.row(i)).sqrt()).collect();
+    let cosine = |i: usize, j: usize| features.row(i).dot(&features.row(j)) / (norms[i] * norms[j]);
+
+    let mut rng = StdRng::seed_from_u64(FASTPAIR_SEED);
+    let tables: Vec<Vec<Array1<f64>>> = (0..FASTPAIR_TABLES).map(|_| {
+        (0..FASTPAIR_HYPERPLANES).map(|_| Array1::from_shape_fn(dim, |_| rng.gen_range(-1.0..1.0))).collect()
+    }).collect();
+
+    // Bucket every point under each hash table up front, so a point's candidate set
+    // is the union of its table buckets instead of every other point.
+    let mut buckets: Vec<HashMap<u32, Vec<usize>>> = vec![HashMap::new(); FASTPAIR_TABLES];
+    for i in 0..n {
+        for (t, hyperplanes) in tables.iter().enumerate() {
+            let key = lsh_bucket_key(features.row(i), hyperplanes);
+            buckets[t].entry(key).or_default().push(i);
+        }
+    }
+
+    let mut comparisons = 0usize;
+
+    let graph = (0..n).map(|i| {
+        let mut candidates: HashSet<usize> = HashSet::new();
+        for (t, hyperplanes) in tables.iter().enumerate() {
+            let key = lsh_bucket_key(features.row(i), hyperplanes);
+            if let Some(bucket) = buckets[t].get(&key) {
+                candidates.extend(bucket.iter().filter(|&&j| j != i));
+            }
+        }
+
+        // Buckets can come up short for points near a cluster edge; fall back to the
+        // remaining points only when the candidate set can't satisfy k.
+        if candidates.len() < k {
+            candidates.extend((0..n).filter(|&j| j != i));
+        }
+
+        comparisons += candidates.len();
+
+        let mut sim_list: Vec<(usize, f64)> = candidates.into_iter().map(|j| (j, cosine(i, j))).collect();
+        sim_list.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+        sim_list.into_iter().take(k).collect()
+    }).collect();
+
+    (graph, comparisons)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{load_and_preprocess, build_graph_from_features};
+    use super::{
+        load_and_preprocess, load_and_preprocess_private, build_graph_from_features,
+        build_graph_fastpair, build_graph_fastpair_counting,
+    };
     use ndarray::{array, Array2};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::HashSet;
 
     #[test]
     fn test_preprocess() {
@@ -186,9 +318,74 @@ mod tests {
             [0.0, 1.0]
         ];
         let graph = build_graph_from_features(&features, 1);
-        assert_eq!(graph[0][0], 1);
-        assert_eq!(graph[1][0], 0);
-        let neighbor_of_2 = graph[2][0];
+        assert_eq!(graph[0][0].0, 1);
+        assert_eq!(graph[1][0].0, 0);
+        let neighbor_of_2 = graph[2][0].0;
         assert!(neighbor_of_2 == 0 || neighbor_of_2 == 1);
     }
+
+    #[test]
+    fn test_preprocess_private_converges_at_large_epsilon() {
+        let data = "price,quality,brand,size
+1.0,10.0,A,Small
+2.0,20.0,B,Medium
+3.0,30.0,A,Medium
+";
+        std::fs::write("test_private.csv", data).unwrap();
+        let cols = ["price", "quality"];
+        let cats = ["brand", "size"];
+
+        let mat = load_and_preprocess("test_private.csv", &cols, &cats).unwrap();
+        let private_mat = load_and_preprocess_private("test_private.csv", &cols, &cats, &[1e9, 1e9]).unwrap();
+
+        for i in 0..mat.nrows() {
+            for j in 0..cols.len() {
+                assert!((mat[[i, j]] - private_mat[[i, j]]).abs() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn test_build_graph_fastpair_matches_brute_force() {
+        let features: Array2<f64> = array![
+            [1.0, 0.0],
+            [1.0, 0.0],
+            [0.0, 1.0]
+        ];
+        let fastpair = build_graph_fastpair(&features, 1);
+        assert_eq!(fastpair[0][0].0, 1);
+        assert_eq!(fastpair[1][0].0, 0);
+        let neighbor_of_2 = fastpair[2][0].0;
+        assert!(neighbor_of_2 == 0 || neighbor_of_2 == 1);
+    }
+
+    #[test]
+    fn test_build_graph_fastpair_is_subquadratic_and_has_reasonable_recall_at_scale() {
+        let n = 3000;
+        let dim = 10;
+        let k = 5;
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let features = Array2::from_shape_fn((n, dim), |_| rng.gen_range(-1.0..1.0));
+
+        let brute = build_graph_from_features(&features, k);
+        let (fastpair, comparisons) = build_graph_fastpair_counting(&features, k);
+
+        // Bucketing should cut the number of cosine comparisons well below the full
+        // n*(n-1) pairwise scan. Counting comparisons (rather than wall-clock elapsed
+        // time) keeps this assertion from flaking under CI load or in a debug build.
+        let brute_force_comparisons = n * (n - 1);
+        assert!(
+            comparisons < brute_force_comparisons / 10,
+            "fastpair did {} comparisons, not much less than brute force's {}", comparisons, brute_force_comparisons
+        );
+
+        let mut total_overlap = 0;
+        for i in 0..n {
+            let brute_neighbors: HashSet<usize> = brute[i].iter().map(|&(j, _)| j).collect();
+            total_overlap += fastpair[i].iter().filter(|&&(j, _)| brute_neighbors.contains(&j)).count();
+        }
+        let recall = total_overlap as f64 / (n * k) as f64;
+        assert!(recall > 0.5, "fastpair recall too low: {}", recall);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,126 @@
+// Module for generating synthetic fashion catalogs, so tests and demos don't depend
+// on shipping a real fashion_products.csv.
+
+use std::error::Error;
+use std::fs::File;
+use std::io::Write;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::preprocessing::Item;
+
+/// Configures the shape of a synthetic catalog: how many products to generate and
+/// the ranges/pools to draw each field from.
+pub struct DatasetConfig<'a> {
+    pub count: usize,
+    pub price_range: (i64, i64),
+    pub rating_range: (f64, f64),
+    pub brands: &'a [&'a str],
+    pub categories: &'a [&'a str],
+    pub colors: &'a [&'a str],
+    pub sizes: &'a [&'a str],
+    pub seed: u64,
+}
+
+/// Generates a synthetic fashion catalog from a `DatasetConfig`, drawing brand,
+/// category, color and size uniformly from their pools and price/rating uniformly
+/// from their ranges. Uses a seedable RNG so repeated runs with the same config are
+/// reproducible, and assigns sequential product IDs starting at 1.
+/// Input: a dataset configuration.
+/// Output: the generated items.
+pub fn generate_dataset(config: &DatasetConfig) -> Vec<Item> {
+    let mut rng = StdRng::seed_from_u64(config.seed);
+
+    (0..config.count).map(|i| {
+        let product = (i + 1) as i64;
+        let brand = config.brands[rng.gen_range(0..config.brands.len())].to_string();
+        let category = config.categories[rng.gen_range(0..config.categories.len())].to_string();
+        let color = config.colors[rng.gen_range(0..config.colors.len())].to_string();
+        let size = config.sizes[rng.gen_range(0..config.sizes.len())].to_string();
+        let price = rng.gen_range(config.price_range.0..=config.price_range.1);
+        let rating = rng.gen_range(config.rating_range.0..=config.rating_range.1);
+
+        Item {
+            product,
+            name: format!("{} {} {}", brand, category, product),
+            brand,
+            category,
+            price,
+            rating,
+            color,
+            size,
+        }
+    }).collect()
+}
+
+/// Writes generated items to a CSV file using the same column layout `load_metadata`
+/// and `load_and_preprocess` expect.
+/// Inputs: the items to write and an output file path.
+/// Output: nothing, or an error if the file could not be written.
+pub fn write_csv(items: &[Item], path: &str) -> Result<(), Box<dyn Error>> {
+    let mut file = File::create(path)?;
+    writeln!(file, "Index,Product ID,Product Name,Brand,Category,Price,Rating,Color,Size")?;
+
+    for (i, item) in items.iter().enumerate() {
+        writeln!(
+            file,
+            "{},{},{},{},{},{},{},{},{}",
+            i, item.product, item.name, item.brand, item.category, item.price, item.rating, item.color, item.size
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate_dataset, write_csv, DatasetConfig};
+    use crate::preprocessing::load_metadata;
+
+    fn test_config(seed: u64) -> DatasetConfig<'static> {
+        DatasetConfig {
+            count: 10,
+            price_range: (10, 100),
+            rating_range: (1.0, 5.0),
+            brands: &["Nike", "Zara", "Puma"],
+            categories: &["Shoes", "Shirts"],
+            colors: &["Black", "White"],
+            sizes: &["Small", "Medium", "Large"],
+            seed,
+        }
+    }
+
+    #[test]
+    fn test_generate_dataset_is_reproducible() {
+        let a = generate_dataset(&test_config(42));
+        let b = generate_dataset(&test_config(42));
+
+        assert_eq!(a.len(), 10);
+        for (item_a, item_b) in a.iter().zip(b.iter()) {
+            assert_eq!(item_a.product, item_b.product);
+            assert_eq!(item_a.brand, item_b.brand);
+            assert_eq!(item_a.category, item_b.category);
+            assert_eq!(item_a.price, item_b.price);
+            assert_eq!(item_a.rating, item_b.rating);
+        }
+    }
+
+    #[test]
+    fn test_write_csv_round_trips_through_load_metadata() {
+        let items = generate_dataset(&test_config(7));
+        write_csv(&items, "test_synthetic.csv").unwrap();
+
+        let loaded = load_metadata("test_synthetic.csv").unwrap();
+
+        assert_eq!(loaded.len(), items.len());
+        for (original, loaded) in items.iter().zip(loaded.iter()) {
+            assert_eq!(original.product, loaded.product);
+            assert_eq!(original.brand, loaded.brand);
+            assert_eq!(original.category, loaded.category);
+            assert_eq!(original.price, loaded.price);
+            assert_eq!(original.color, loaded.color);
+            assert_eq!(original.size, loaded.size);
+        }
+    }
+}